@@ -0,0 +1,124 @@
+//! error system
+use std::{error, fmt, io, num, str};
+
+///Represents errors that can occur while parsing a response or a `Uri`.
+#[derive(Debug, PartialEq)]
+pub enum ParseErr {
+    Utf8(str::Utf8Error),
+    Int(num::ParseIntError),
+    StatusErr,
+    HeadersErr,
+    UriErr,
+}
+
+impl error::Error for ParseErr {
+    fn description(&self) -> &str {
+        use self::ParseErr::*;
+
+        match self {
+            Utf8(_) => "invalid character",
+            Int(_) => "cannot parse number",
+            StatusErr => "status line contains invalid values",
+            HeadersErr => "headers contain invalid values",
+            UriErr => "uri contains invalid characters",
+        }
+    }
+}
+
+impl fmt::Display for ParseErr {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use self::ParseErr::*;
+
+        let err = match self {
+            Utf8(_) => "invalid character",
+            Int(_) => "cannot parse number",
+            StatusErr => "status line contains invalid values",
+            HeadersErr => "headers contain invalid values",
+            UriErr => "uri contains invalid characters",
+        };
+
+        write!(f, "ParseErr: {}", err)
+    }
+}
+
+impl From<str::Utf8Error> for ParseErr {
+    fn from(e: str::Utf8Error) -> Self {
+        ParseErr::Utf8(e)
+    }
+}
+
+impl From<num::ParseIntError> for ParseErr {
+    fn from(e: num::ParseIntError) -> Self {
+        ParseErr::Int(e)
+    }
+}
+
+///Represents errors that can occur while sending an HTTP request.
+#[derive(Debug)]
+pub enum Error {
+    IO(io::Error),
+    Parse(ParseErr),
+    Timeout,
+    TooManyRedirects,
+    Tls,
+}
+
+impl error::Error for Error {
+    fn description(&self) -> &str {
+        use self::Error::*;
+
+        match self {
+            IO(_) => "IO error",
+            Parse(_) => "parsing error",
+            Timeout => "connection timed out",
+            TooManyRedirects => "too many redirects",
+            Tls => "TLS error",
+        }
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use self::Error::*;
+
+        let err = match self {
+            IO(_) => "IO error",
+            Parse(err) => return err.fmt(f),
+            Timeout => "connection timed out",
+            TooManyRedirects => "too many redirects",
+            Tls => "TLS error",
+        };
+
+        write!(f, "Error: {}", err)
+    }
+}
+
+impl From<io::Error> for Error {
+    fn from(e: io::Error) -> Self {
+        // A socket read/write that trips `set_read_timeout`/`set_write_timeout`
+        // (or a connect timeout) surfaces as `WouldBlock`/`TimedOut`; report it
+        // as a distinct timeout rather than a generic IO error.
+        match e.kind() {
+            io::ErrorKind::TimedOut | io::ErrorKind::WouldBlock => Error::Timeout,
+            _ => Error::IO(e),
+        }
+    }
+}
+
+impl From<ParseErr> for Error {
+    fn from(e: ParseErr) -> Self {
+        Error::Parse(e)
+    }
+}
+
+impl From<native_tls::Error> for Error {
+    fn from(_e: native_tls::Error) -> Self {
+        Error::Tls
+    }
+}
+
+impl<S> From<native_tls::HandshakeError<S>> for Error {
+    fn from(_e: native_tls::HandshakeError<S>) -> Self {
+        Error::Tls
+    }
+}
@@ -4,42 +4,243 @@ use crate::{
     response::{Headers, Response, CR_LF_2},
     uri::Uri,
 };
-use native_tls::TlsConnector;
+use native_tls::{TlsConnector, TlsStream};
 use std::{
+    collections::HashMap,
     fmt,
     io::{self, Read, Write},
-    net::TcpStream,
+    net::{TcpStream, ToSocketAddrs},
+    time::{Duration, Instant},
 };
 
 const CR_LF: &str = "\r\n";
 const HTTP_V: &str = "HTTP/1.1";
 
-///Copies data from `reader` to `writer` until the specified `val`ue is reached.
-///Returns how many bytes has been read.
-pub fn copy_until<R, W>(reader: &mut R, writer: &mut W, val: &[u8]) -> Result<usize, io::Error>
+///Copies data from `reader` to `writer` up to and including the first
+///occurrence of the delimiter `val`.
+///
+///Reads in blocks rather than a byte at a time, scanning each new block for
+///`val` with a rolling match that spans block boundaries. Everything up to and
+///including the delimiter is written to `writer`; any bytes already read past
+///the delimiter are returned so the caller can prepend them to the body stream.
+///If the delimiter is never seen (EOF) all the data is written and an empty
+///leftover is returned.
+pub fn copy_until<R, W>(reader: &mut R, writer: &mut W, val: &[u8]) -> Result<Vec<u8>, io::Error>
 where
     R: Read + ?Sized,
     W: Write + ?Sized,
 {
     let mut buf = Vec::with_capacity(200);
+    let mut block = [0; 512];
 
-    let mut pre_buf = [0; 10];
-    let mut read = reader.read(&mut pre_buf)?;
-    buf.extend(&pre_buf[..read]);
+    loop {
+        let read = reader.read(&mut block)?;
+        if read == 0 {
+            break;
+        }
 
-    for byte in reader.bytes() {
-        buf.push(byte?);
-        read += 1;
+        buf.extend_from_slice(&block[..read]);
 
-        if &buf[(buf.len() - val.len())..] == val {
-            break;
+        // Start the scan far enough back that a delimiter straddling the
+        // previous block boundary is still found, guarding the underflow when
+        // fewer than `val.len()` bytes have been read so far.
+        let start = buf.len().saturating_sub(read + val.len() - 1);
+
+        if let Some(pos) = find_subslice(&buf[start..], val) {
+            let end = start + pos + val.len();
+            writer.write_all(&buf[..end])?;
+            writer.flush()?;
+
+            return Ok(buf.split_off(end));
         }
     }
 
     writer.write_all(&buf)?;
     writer.flush()?;
 
-    Ok(read)
+    Ok(Vec::new())
+}
+
+///Returns the index of the first occurrence of `needle` in `haystack`, or
+///`None` when `needle` is empty or longer than `haystack`.
+fn find_subslice(haystack: &[u8], needle: &[u8]) -> Option<usize> {
+    if needle.is_empty() || haystack.len() < needle.len() {
+        return None;
+    }
+
+    haystack.windows(needle.len()).position(|w| w == needle)
+}
+
+///Reads a response body from `reader` into `writer`, framing it according to
+///the response headers: an explicit `Content-Length`, a chunked
+///`Transfer-Encoding`, or — when neither is present — everything up to EOF.
+///`close` tells whether this connection will be closed after the response; when
+///no framing is present the body is only read to EOF if so, since EOF is the
+///sole delimiter and a kept-alive socket never reaches it.
+///
+///Returns whether the connection is safe to reuse afterwards: `true` only when
+///the whole body was consumed against a known frame (length, chunked or a
+///bodiless status). A close-delimited or unframed response leaves the socket
+///unusable for pooling.
+fn read_body<R, W>(
+    reader: &mut R,
+    status: u16,
+    headers: &Headers,
+    close: bool,
+    writer: &mut W,
+) -> Result<bool, io::Error>
+where
+    R: Read + ?Sized,
+    W: Write + ?Sized,
+{
+    // 1xx, 204 and 304 responses never carry a body, regardless of headers.
+    if is_bodiless_status(status) {
+        return Ok(!close);
+    }
+
+    if let Some(encoding) = headers.get("Transfer-Encoding") {
+        if encoding.to_lowercase().contains("chunked") {
+            read_chunked(reader, writer)?;
+            return Ok(!close);
+        }
+    }
+
+    if let Some(len) = headers.get("Content-Length") {
+        let len = len.trim().parse::<usize>().map_err(|_| {
+            io::Error::new(io::ErrorKind::InvalidData, "malformed Content-Length")
+        })?;
+
+        read_sized(reader, writer, len)?;
+        return Ok(!close);
+    }
+
+    // No framing signal: the body is close-delimited. Read to EOF when this
+    // connection will close (true on the single-use `Request` path, which sends
+    // `Connection: Close` unless its headers are replaced); otherwise a
+    // kept-alive socket would block forever, so leave the body unread. Either
+    // way the socket is not in a known state, so it must not be pooled.
+    if close {
+        io::copy(reader, writer)?;
+        writer.flush()?;
+    }
+
+    Ok(false)
+}
+
+///Whether `status` denotes a response that carries no message body.
+fn is_bodiless_status(status: u16) -> bool {
+    matches!(status, 100..=199 | 204 | 304)
+}
+
+///Whether `status` is a 1xx interim response rather than a final one.
+fn is_interim_status(status: u16) -> bool {
+    matches!(status, 100..=199)
+}
+
+///Whether the header set asks for the connection to be closed.
+fn headers_want_close(headers: &Headers) -> bool {
+    headers
+        .get("Connection")
+        .map(|v| v.to_lowercase().contains("close"))
+        .unwrap_or(false)
+}
+
+///Copies exactly `len` bytes from `reader` to `writer`.
+fn read_sized<R, W>(reader: &mut R, writer: &mut W, len: usize) -> Result<(), io::Error>
+where
+    R: Read + ?Sized,
+    W: Write + ?Sized,
+{
+    let mut remaining = len;
+    let mut buf = [0; 4096];
+
+    while remaining > 0 {
+        let want = remaining.min(buf.len());
+        let read = reader.read(&mut buf[..want])?;
+
+        if read == 0 {
+            return Err(io::Error::new(
+                io::ErrorKind::UnexpectedEof,
+                "body shorter than Content-Length",
+            ));
+        }
+
+        writer.write_all(&buf[..read])?;
+        remaining -= read;
+    }
+
+    writer.flush()
+}
+
+///Decodes a `Transfer-Encoding: chunked` body, writing only the payload bytes
+///to `writer` and consuming the terminating zero chunk and any trailer block.
+fn read_chunked<R, W>(reader: &mut R, writer: &mut W) -> Result<(), io::Error>
+where
+    R: Read + ?Sized,
+    W: Write + ?Sized,
+{
+    loop {
+        let line = read_line(reader)?;
+        // A chunk-size line may carry extensions after a `;`; ignore them.
+        let size_part = line.split(';').next().unwrap_or("").trim();
+        let size = usize::from_str_radix(size_part, 16)
+            .map_err(|_| io::Error::new(io::ErrorKind::InvalidData, "malformed chunk size"))?;
+
+        if size == 0 {
+            // Consume the (possibly empty) trailer block up to the final blank line.
+            loop {
+                if read_line(reader)?.is_empty() {
+                    break;
+                }
+            }
+            break;
+        }
+
+        let mut remaining = size;
+        let mut buf = [0; 4096];
+        while remaining > 0 {
+            let want = remaining.min(buf.len());
+            let read = reader.read(&mut buf[..want])?;
+
+            if read == 0 {
+                return Err(io::Error::new(
+                    io::ErrorKind::UnexpectedEof,
+                    "truncated chunk payload",
+                ));
+            }
+
+            writer.write_all(&buf[..read])?;
+            remaining -= read;
+        }
+
+        // Each chunk's payload is followed by a bare CRLF.
+        read_line(reader)?;
+    }
+
+    writer.flush()
+}
+
+///Reads a single CRLF-terminated line, returning it without the trailing CRLF.
+fn read_line<R: Read + ?Sized>(reader: &mut R) -> Result<String, io::Error> {
+    let mut line = Vec::new();
+    let mut byte = [0; 1];
+
+    loop {
+        if reader.read(&mut byte)? == 0 {
+            break;
+        }
+
+        if byte[0] == b'\n' {
+            break;
+        }
+
+        if byte[0] != b'\r' {
+            line.push(byte[0]);
+        }
+    }
+
+    String::from_utf8(line)
+        .map_err(|_| io::Error::new(io::ErrorKind::InvalidData, "non-utf8 chunk header"))
 }
 
 ///HTTP request methods
@@ -140,14 +341,109 @@ impl<'a> RequestBuilder<'a> {
         T: Write + Read,
         U: Write,
     {
-        self.write_msg(stream, &self.parse_msg())?;
-        let res = self.read_head(stream)?;
+        self.send_framed(stream, writer).map(|(res, _)| res)
+    }
 
-        if self.method != Method::HEAD {
-            io::copy(stream, writer)?;
+    ///Like [`send`](RequestBuilder::send) but also reports whether the
+    ///connection is safe to reuse afterwards (its body was fully consumed
+    ///against a known frame). Used by [`Client`] to decide whether to pool.
+    fn send_framed<T, U>(
+        &self,
+        stream: &mut T,
+        writer: &mut U,
+    ) -> Result<(Response, bool), error::Error>
+    where
+        T: Write + Read,
+        U: Write,
+    {
+        if self.expects_continue() {
+            return self.send_expecting_continue(stream, writer);
         }
 
-        Ok(res)
+        self.write_msg(stream, &self.parse_msg())?;
+        let (res, leftover) = self.read_head(stream)?;
+        let close = self.request_wants_close() || headers_want_close(res.headers());
+
+        let reusable = if self.method != Method::HEAD {
+            let mut body = io::Cursor::new(leftover).chain(&mut *stream);
+            read_body(&mut body, u16::from(res.status_code()), res.headers(), close, writer)?
+        } else {
+            !close
+        };
+
+        Ok((res, reusable))
+    }
+
+    ///Whether this request asked the server to close the connection.
+    fn request_wants_close(&self) -> bool {
+        self.headers
+            .get("Connection")
+            .map(|v| v.to_lowercase().contains("close"))
+            .unwrap_or(false)
+    }
+
+    ///Whether this request opted into the `Expect: 100-continue` handshake and
+    ///actually carries a body to withhold.
+    fn expects_continue(&self) -> bool {
+        self.body.is_some()
+            && self
+                .headers
+                .get("Expect")
+                .map(|v| v.to_lowercase().contains("100-continue"))
+                .unwrap_or(false)
+    }
+
+    ///Performs the `Expect: 100-continue` exchange: write the head, then read
+    ///status lines until a final (non-1xx) response. On a `100 Continue` the
+    ///body is uploaded and reading continues; any other interim (e.g.
+    ///`102 Processing`) is skipped. A final status sent in place of a `100`
+    ///(e.g. `417`/`413`) is returned directly without the body ever leaving the
+    ///client. Bytes read past each interim head are carried into the next read
+    ///so a server that pipelines its final response is parsed correctly.
+    fn send_expecting_continue<T, U>(
+        &self,
+        stream: &mut T,
+        writer: &mut U,
+    ) -> Result<(Response, bool), error::Error>
+    where
+        T: Write + Read,
+        U: Write,
+    {
+        self.write_msg(stream, &self.head_msg())?;
+
+        let mut pending = Vec::new();
+        loop {
+            let (res, leftover) = {
+                let mut reader = io::Cursor::new(pending).chain(&mut *stream);
+                self.read_head(&mut reader)?
+            };
+            pending = leftover;
+
+            let status = u16::from(res.status_code());
+
+            if status == 100 {
+                // Server is ready for the body; send it and read the final head.
+                if let Some(body) = self.body {
+                    self.write_msg(stream, &body)?;
+                }
+                continue;
+            }
+
+            if is_interim_status(status) {
+                // Other 1xx responses are informational; keep reading.
+                continue;
+            }
+
+            let close = self.request_wants_close() || headers_want_close(res.headers());
+            let reusable = if self.method != Method::HEAD {
+                let mut body = io::Cursor::new(pending).chain(&mut *stream);
+                read_body(&mut body, status, res.headers(), close, writer)?
+            } else {
+                !close
+            };
+
+            return Ok((res, reusable));
+        }
     }
 
     ///Writes message to `stream` and flashes it
@@ -162,16 +458,32 @@ impl<'a> RequestBuilder<'a> {
         Ok(())
     }
 
-    ///Reads head of server's response
-    pub fn read_head<T: Read>(&self, stream: &mut T) -> Result<Response, error::Error> {
+    ///Reads head of server's response.
+    ///
+    ///Returns the parsed `Response` together with any body bytes that were read
+    ///past the head's terminating `CR_LF_2` and must be prepended to the body.
+    pub fn read_head<T: Read>(&self, stream: &mut T) -> Result<(Response, Vec<u8>), error::Error> {
         let mut head = Vec::with_capacity(200);
-        copy_until(stream, &mut head, &CR_LF_2)?;
+        let leftover = copy_until(stream, &mut head, &CR_LF_2)?;
 
-        Response::from_head(&head)
+        Ok((Response::from_head(&head)?, leftover))
     }
 
     ///Parses request message for this `RequestBuilder`
     pub fn parse_msg(&self) -> Vec<u8> {
+        let mut request_msg = self.head_msg();
+
+        if let Some(b) = &self.body {
+            request_msg.extend(*b);
+        }
+
+        request_msg
+    }
+
+    ///Parses just the head (request line + headers + blank line) of the request
+    ///message, without the body. Used by the `Expect: 100-continue` handshake to
+    ///send the head ahead of the body.
+    fn head_msg(&self) -> Vec<u8> {
         let request_line = format!(
             "{} {} {}{}",
             self.method,
@@ -186,23 +498,34 @@ impl<'a> RequestBuilder<'a> {
             .map(|(k, v)| format!("{}: {}{}", k, v, CR_LF))
             .collect();
 
-        let mut request_msg = (request_line + &headers + CR_LF).as_bytes().to_vec();
-
-        if let Some(b) = &self.body {
-            request_msg.extend(*b);
-        }
-
-        request_msg
+        (request_line + &headers + CR_LF).as_bytes().to_vec()
     }
 }
 
+///Status codes that carry a `Location` header this client knows how to follow.
+fn is_redirect(status: u16) -> bool {
+    matches!(status, 301 | 302 | 303 | 307 | 308)
+}
+
 ///Relatively higher-level struct for making HTTP requests.
 ///
 ///It creates stream (`TcpStream` or `TlsStream`) appropriate for the type of uri (`http`/`https`)
 ///By default it closes connection after completion of the response.
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Debug)]
 pub struct Request<'a> {
     inner: RequestBuilder<'a>,
+    max_redirects: usize,
+    timeouts: Timeouts,
+    connector: Option<TlsConnector>,
+}
+
+///Per-phase socket timeouts applied by [`Request::send`]. A `None` field leaves
+///that phase blocking indefinitely, matching the original behaviour.
+#[derive(Clone, Debug, Default, PartialEq)]
+struct Timeouts {
+    connect: Option<Duration>,
+    read: Option<Duration>,
+    write: Option<Duration>,
 }
 
 impl<'a> Request<'a> {
@@ -211,7 +534,62 @@ impl<'a> Request<'a> {
         let mut builder = RequestBuilder::new(&uri);
         builder.header("Connection", "Close");
 
-        Request { inner: builder }
+        Request {
+            inner: builder,
+            max_redirects: 0,
+            timeouts: Timeouts::default(),
+            connector: None,
+        }
+    }
+
+    ///Supplies a pre-configured `TlsConnector` for `https` requests.
+    ///
+    ///By default `send` builds a fresh `TlsConnector::new()` with the platform's
+    ///trust roots. Injecting one lets callers pin roots, add client certificates
+    ///or accept self-signed certs in tests. The connector is ignored for plain
+    ///`http` requests.
+    pub fn connector(&mut self, connector: TlsConnector) -> &mut Self {
+        self.connector = Some(connector);
+        self
+    }
+
+    ///Sets a single `timeout` applied to the connect, read and write phases.
+    ///
+    ///A request that makes no progress within this duration fails with
+    ///`error::Error::Timeout` instead of blocking forever.
+    pub fn timeout(&mut self, timeout: Duration) -> &mut Self {
+        self.timeouts.connect = Some(timeout);
+        self.timeouts.read = Some(timeout);
+        self.timeouts.write = Some(timeout);
+        self
+    }
+
+    ///Sets the timeout for establishing the TCP connection.
+    pub fn connect_timeout(&mut self, timeout: Duration) -> &mut Self {
+        self.timeouts.connect = Some(timeout);
+        self
+    }
+
+    ///Sets the timeout for reading the response off the socket.
+    pub fn read_timeout(&mut self, timeout: Duration) -> &mut Self {
+        self.timeouts.read = Some(timeout);
+        self
+    }
+
+    ///Sets the timeout for writing the request to the socket.
+    pub fn write_timeout(&mut self, timeout: Duration) -> &mut Self {
+        self.timeouts.write = Some(timeout);
+        self
+    }
+
+    ///Sets how many 3xx redirects `send` will follow automatically.
+    ///
+    ///Redirect following is opt-in: the default of `0` leaves the original
+    ///behaviour of returning the 3xx response untouched. When the limit is
+    ///exceeded `send` returns `error::Error::TooManyRedirects`.
+    pub fn redirect_policy(&mut self, max: usize) -> &mut Self {
+        self.max_redirects = max;
+        self
     }
 
     ///Replaces all it's headers with headers passed to the function
@@ -247,23 +625,420 @@ impl<'a> Request<'a> {
     ///
     ///Creates `TcpStream` (and wraps it with `TlsStream` if needed). Writes request message
     ///to created stream. Returns response for this request. Writes response's body to `writer`.
+    ///
+    ///When a redirect policy has been set (see [`redirect_policy`](Request::redirect_policy))
+    ///any 301, 302, 303, 307 or 308 response carrying a `Location` header is followed, rewiring the stream when the
+    ///host or scheme changes, until a non-redirect response is reached or the hop limit is hit.
     pub fn send<T: Write>(&self, writer: &mut T) -> Result<Response, error::Error> {
-        let mut stream = TcpStream::connect((
-            self.inner.uri.host().unwrap_or(""),
-            self.inner.uri.corr_port(),
-        ))?;
+        let origin_scheme = self.inner.uri.scheme().to_string();
+        let origin_host = self.inner.uri.host().unwrap_or("").to_string();
+        let origin_port = self.inner.uri.corr_port();
+
+        let mut uri = self.inner.uri.clone();
+        let mut method = self.inner.method.clone();
+        let mut body = self.inner.body;
+        let mut body_dropped = false;
+        let mut hops = 0;
+
+        loop {
+            // Headers that carry credentials must not follow a redirect to a
+            // different origin, matching how mature clients behave.
+            let cross_origin = uri.scheme() != origin_scheme
+                || uri.host().unwrap_or("") != origin_host
+                || uri.corr_port() != origin_port;
+
+            let headers = self.headers_for_hop(cross_origin, body_dropped);
+
+            let mut builder = RequestBuilder::new(&uri);
+            builder.headers(headers);
+            builder.header("Host", uri.host().unwrap_or(""));
+            builder.method(method.clone());
+            if let Some(b) = body {
+                builder.body(b);
+            }
+
+            // Intermediate responses are drained into a scratch buffer so the
+            // caller's `writer` only ever receives the body of the final hop.
+            let mut sink = Vec::new();
+            let res = open_and_send(
+                &uri,
+                &builder,
+                &self.timeouts,
+                self.connector.as_ref(),
+                &mut sink,
+            )?;
+
+            if !is_redirect(u16::from(res.status_code())) {
+                writer.write_all(&sink)?;
+                return Ok(res);
+            }
+
+            // Redirect following is opt-in: a policy of `0` returns the 3xx
+            // response untouched. Otherwise follow until a non-redirect is
+            // reached, erroring once the hop limit is exceeded.
+            if self.max_redirects == 0 {
+                writer.write_all(&sink)?;
+                return Ok(res);
+            }
+
+            let location = match res.headers().get("Location") {
+                Some(loc) => loc.to_string(),
+                None => {
+                    writer.write_all(&sink)?;
+                    return Ok(res);
+                }
+            };
+
+            if hops >= self.max_redirects {
+                return Err(error::Error::TooManyRedirects);
+            }
+
+            uri = resolve_redirect(&uri, &location)?;
+
+            // 303 always downgrades to GET and drops the body; by convention we
+            // do the same for 301/302 on requests other than GET and HEAD.
+            // 307/308 preserve both.
+            match u16::from(res.status_code()) {
+                303 => {
+                    method = Method::GET;
+                    body = None;
+                    body_dropped = true;
+                }
+                301 | 302 if method != Method::GET && method != Method::HEAD => {
+                    method = Method::GET;
+                    body = None;
+                    body_dropped = true;
+                }
+                _ => {}
+            }
+
+            hops += 1;
+        }
+    }
+
+    ///Builds the headers for one redirect hop from the original request's
+    ///headers, dropping credential-bearing headers on a cross-origin hop and
+    ///body-describing headers once the body has been dropped by a downgrade.
+    fn headers_for_hop(&self, cross_origin: bool, body_dropped: bool) -> Headers {
+        let mut headers = Headers::new();
 
-        if self.inner.uri.scheme() == "https" {
-            let connector = TlsConnector::new()?;
-            let mut stream = connector.connect(self.inner.uri.host().unwrap_or(""), stream)?;
+        for (k, v) in self.inner.headers.iter() {
+            let key = k.to_lowercase();
 
-            self.inner.send(&mut stream, writer)
+            if cross_origin && (key == "authorization" || key == "cookie") {
+                continue;
+            }
+
+            if body_dropped
+                && (key == "content-length" || key == "content-type" || key == "transfer-encoding")
+            {
+                continue;
+            }
+
+            headers.insert(k, v);
+        }
+
+        headers
+    }
+}
+
+///Opens a stream appropriate for `uri` (`TcpStream`, wrapped in `TlsStream` for
+///`https`), sends `builder` over it and writes the response body to `writer`.
+fn open_and_send<T: Write>(
+    uri: &Uri,
+    builder: &RequestBuilder,
+    timeouts: &Timeouts,
+    connector: Option<&TlsConnector>,
+    writer: &mut T,
+) -> Result<Response, error::Error> {
+    let stream = connect(uri, timeouts)?;
+    stream.set_read_timeout(timeouts.read)?;
+    stream.set_write_timeout(timeouts.write)?;
+
+    if uri.scheme() == "https" {
+        let owned;
+        let connector = match connector {
+            Some(connector) => connector,
+            None => {
+                owned = TlsConnector::new()?;
+                &owned
+            }
+        };
+        let mut stream = connector.connect(uri.host().unwrap_or(""), stream)?;
+
+        builder.send(&mut stream, writer)
+    } else {
+        let mut stream = stream;
+        builder.send(&mut stream, writer)
+    }
+}
+
+///Opens a `TcpStream` to `uri`, honouring the connect timeout if one is set.
+fn connect(uri: &Uri, timeouts: &Timeouts) -> Result<TcpStream, error::Error> {
+    match timeouts.connect {
+        Some(timeout) => {
+            let addr = (uri.host().unwrap_or(""), uri.corr_port())
+                .to_socket_addrs()?
+                .next()
+                .ok_or_else(|| {
+                    io::Error::new(io::ErrorKind::NotFound, "could not resolve host")
+                })?;
+
+            Ok(TcpStream::connect_timeout(&addr, timeout)?)
+        }
+        None => Ok(TcpStream::connect((uri.host().unwrap_or(""), uri.corr_port()))?),
+    }
+}
+
+///Resolves a `Location` header value against the `Uri` of the request that
+///produced it, handling absolute URLs, protocol-relative `//host` forms and
+///absolute/relative paths.
+fn resolve_redirect(base: &Uri, location: &str) -> Result<Uri, error::Error> {
+    let target = if has_scheme(location) {
+        location.to_string()
+    } else if let Some(rest) = location.strip_prefix("//") {
+        format!("{}://{}", base.scheme(), rest)
+    } else {
+        let authority = match base.corr_port() {
+            port if port == default_port(base.scheme()) => base.host().unwrap_or("").to_string(),
+            port => format!("{}:{}", base.host().unwrap_or(""), port),
+        };
+
+        let path = if location.starts_with('/') {
+            location.to_string()
         } else {
-            self.inner.send(&mut stream, writer)
+            // Merge a relative reference against the base path's directory,
+            // per RFC 3986 (base `/a/b/c` + `d` -> `/a/b/d`).
+            merge_paths(base.resource(), location)
+        };
+
+        format!("{}://{}{}", base.scheme(), authority, path)
+    };
+
+    Ok(target.parse::<Uri>()?)
+}
+
+///Whether `location` is an absolute URI, i.e. begins with a valid `scheme://`.
+///Guards against a relative path whose query merely contains `://`.
+fn has_scheme(location: &str) -> bool {
+    match location.find("://") {
+        Some(pos) if pos > 0 => {
+            let scheme = &location[..pos];
+            scheme.chars().next().map_or(false, |c| c.is_ascii_alphabetic())
+                && scheme
+                    .chars()
+                    .all(|c| c.is_ascii_alphanumeric() || matches!(c, '+' | '-' | '.'))
+        }
+        _ => false,
+    }
+}
+
+///Merges a relative reference against the directory of `base_path`, discarding
+///the base query string. `base_path` is the request-target of the base `Uri`.
+fn merge_paths(base_path: &str, location: &str) -> String {
+    let path_only = base_path
+        .split(|c| c == '?' || c == '#')
+        .next()
+        .unwrap_or("/");
+
+    match path_only.rfind('/') {
+        Some(i) => format!("{}{}", &path_only[..=i], location),
+        None => format!("/{}", location),
+    }
+}
+
+///Default port for a known scheme, used when rebuilding an authority.
+fn default_port(scheme: &str) -> u16 {
+    match scheme {
+        "https" => 443,
+        _ => 80,
+    }
+}
+
+///A concrete connection, either plain or TLS-wrapped, that a [`Client`] can
+///hold idle and hand back out for reuse.
+#[derive(Debug)]
+enum Stream {
+    Http(TcpStream),
+    Https(TlsStream<TcpStream>),
+}
+
+impl Read for Stream {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        match self {
+            Stream::Http(s) => s.read(buf),
+            Stream::Https(s) => s.read(buf),
+        }
+    }
+}
+
+impl Write for Stream {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        match self {
+            Stream::Http(s) => s.write(buf),
+            Stream::Https(s) => s.write(buf),
+        }
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        match self {
+            Stream::Http(s) => s.flush(),
+            Stream::Https(s) => s.flush(),
+        }
+    }
+}
+
+///Identifies a connection target: `(scheme, host, port)`.
+type Origin = (String, String, u16);
+
+///A pooled connection together with the moment it became idle.
+struct Idle {
+    stream: Stream,
+    since: Instant,
+}
+
+///Higher-level client that keeps connections alive and reuses them.
+///
+///Unlike [`Request`], which opens a fresh `TcpStream` (and TLS handshake) per
+///call and closes it afterwards, a `Client` owns a pool of idle streams keyed by
+///`(scheme, host, port)`. Once a response body has been fully consumed the
+///stream is returned to the pool and reused by the next request to the same
+///origin, skipping the connect and handshake.
+pub struct Client {
+    idle: HashMap<Origin, Vec<Idle>>,
+    max_idle_per_host: usize,
+    idle_timeout: Option<Duration>,
+    connector: Option<TlsConnector>,
+}
+
+impl Default for Client {
+    fn default() -> Client {
+        Client::new()
+    }
+}
+
+impl Client {
+    ///Creates a new `Client` with an empty pool.
+    pub fn new() -> Client {
+        Client {
+            idle: HashMap::new(),
+            max_idle_per_host: 8,
+            idle_timeout: Some(Duration::from_secs(90)),
+            connector: None,
+        }
+    }
+
+    ///Supplies a pre-configured `TlsConnector` for pooled `https` connections,
+    ///mirroring [`Request::connector`]. Without one, `TlsConnector::new()` is
+    ///used. The connector is ignored for plain `http`.
+    pub fn connector(&mut self, connector: TlsConnector) -> &mut Self {
+        self.connector = Some(connector);
+        self
+    }
+
+    ///Sets how many idle streams are kept per origin; once that many are idle,
+    ///further streams are dropped instead of pooled.
+    pub fn max_idle_per_host(&mut self, max: usize) -> &mut Self {
+        self.max_idle_per_host = max;
+        self
+    }
+
+    ///Sets how long an idle stream may sit in the pool before being discarded.
+    pub fn idle_timeout(&mut self, timeout: Duration) -> &mut Self {
+        self.idle_timeout = Some(timeout);
+        self
+    }
+
+    ///Sends `builder` to its origin, reusing a pooled connection when possible.
+    ///
+    ///A dead pooled stream is transparently discarded and retried on a fresh
+    ///connection. The stream is returned to the pool afterwards only if its body
+    ///was fully consumed against a known frame and the response did not ask to
+    ///close it.
+    pub fn send<T: Write>(
+        &mut self,
+        builder: &RequestBuilder,
+        writer: &mut T,
+    ) -> Result<Response, error::Error> {
+        let uri = builder.uri;
+        let origin: Origin = (
+            uri.scheme().to_string(),
+            uri.host().unwrap_or("").to_string(),
+            uri.corr_port(),
+        );
+
+        // A pooled stream may die mid-body; buffer its output and only hand it
+        // to the caller on success, so a failed attempt never leaves a partial
+        // body in `writer` to be concatenated with the retry.
+        while let Some(mut stream) = self.take_idle(&origin) {
+            let mut buf = Vec::new();
+            if let Ok((res, reusable)) = builder.send_framed(&mut stream, &mut buf) {
+                writer.write_all(&buf)?;
+                if reusable {
+                    self.store_idle(origin, stream);
+                }
+                return Ok(res);
+            }
+            // Pooled stream was dead; drop it and try the next one.
+        }
+
+        let mut stream = open_stream(uri, self.connector.as_ref())?;
+        let (res, reusable) = builder.send_framed(&mut stream, writer)?;
+        if reusable {
+            self.store_idle(origin, stream);
+        }
+
+        Ok(res)
+    }
+
+    ///Pops a non-expired idle stream for `origin`, if any, discarding expired
+    ///ones on the way. Whether the stream is still alive is not checked here.
+    fn take_idle(&mut self, origin: &Origin) -> Option<Stream> {
+        let streams = self.idle.get_mut(origin)?;
+
+        while let Some(idle) = streams.pop() {
+            match self.idle_timeout {
+                Some(timeout) if idle.since.elapsed() > timeout => continue,
+                _ => return Some(idle.stream),
+            }
+        }
+
+        None
+    }
+
+    ///Returns a reusable stream to the pool, respecting the per-host cap.
+    fn store_idle(&mut self, origin: Origin, stream: Stream) {
+        let streams = self.idle.entry(origin).or_default();
+        if streams.len() < self.max_idle_per_host {
+            streams.push(Idle {
+                stream,
+                since: Instant::now(),
+            });
         }
     }
 }
 
+///Opens a `Stream` for `uri`, performing the TLS handshake for `https` with the
+///supplied connector, or a default `TlsConnector::new()` when none is given.
+fn open_stream(uri: &Uri, connector: Option<&TlsConnector>) -> Result<Stream, error::Error> {
+    let stream = TcpStream::connect((uri.host().unwrap_or(""), uri.corr_port()))?;
+
+    if uri.scheme() == "https" {
+        let owned;
+        let connector = match connector {
+            Some(connector) => connector,
+            None => {
+                owned = TlsConnector::new()?;
+                &owned
+            }
+        };
+        let stream = connector.connect(uri.host().unwrap_or(""), stream)?;
+        Ok(Stream::Https(stream))
+    } else {
+        Ok(Stream::Http(stream))
+    }
+}
+
 ///Creates and sends GET request. Returns response for this request.
 pub fn get<T: AsRef<str>, U: Write>(uri: T, writer: &mut U) -> Result<Response, error::Error> {
     let uri = uri.as_ref().parse::<Uri>()?;
@@ -312,8 +1087,47 @@ mod tests {
         let mut reader = Cursor::new(reader);
         let mut writer = Vec::new();
 
-        copy_until(&mut reader, &mut writer, &CR_LF_2).unwrap();
+        let leftover = copy_until(&mut reader, &mut writer, &CR_LF_2).unwrap();
         assert_eq!(writer, &RESPONSE_H[..]);
+        assert_eq!(leftover, b"<html>hello</html>\r\n\r\nhello");
+    }
+
+    #[test]
+    fn copy_until_truncated() {
+        // Shorter than the delimiter; must not panic and writes what it read.
+        let mut reader = Cursor::new(b"hi".to_vec());
+        let mut writer = Vec::new();
+
+        let leftover = copy_until(&mut reader, &mut writer, &CR_LF_2).unwrap();
+        assert_eq!(writer, b"hi");
+        assert!(leftover.is_empty());
+    }
+
+    #[test]
+    fn read_sized_body() {
+        let mut reader = Cursor::new(b"hello world".to_vec());
+        let mut writer = Vec::new();
+
+        read_sized(&mut reader, &mut writer, 5).unwrap();
+        assert_eq!(writer, b"hello");
+    }
+
+    #[test]
+    fn read_chunked_body() {
+        let mut reader = Cursor::new(b"4\r\nWiki\r\n5\r\npedia\r\n0\r\n\r\n".to_vec());
+        let mut writer = Vec::new();
+
+        read_chunked(&mut reader, &mut writer).unwrap();
+        assert_eq!(writer, b"Wikipedia");
+    }
+
+    #[test]
+    fn read_chunked_ignores_extensions() {
+        let mut reader = Cursor::new(b"4;name=value\r\nWiki\r\n0\r\n\r\n".to_vec());
+        let mut writer = Vec::new();
+
+        read_chunked(&mut reader, &mut writer).unwrap();
+        assert_eq!(writer, b"Wiki");
     }
 
     #[test]